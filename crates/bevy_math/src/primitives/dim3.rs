@@ -1,5 +1,5 @@
 use super::{InvalidDirectionError, Primitive3d};
-use crate::Vec3;
+use crate::{Mat3, Quat, Vec2, Vec3};
 
 /// A normalized vector pointing in a direction in 3D space
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -139,6 +139,22 @@ impl Plane3d {
             normal: Direction3d::new(normal).expect("normal must be nonzero and finite"),
         }
     }
+
+    /// The signed distance from `point` to the plane.
+    ///
+    /// The distance is positive on the side the [`normal`](Self::normal) points towards
+    /// and negative on the other side.
+    #[inline]
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        point.dot(*self.normal)
+    }
+
+    /// Finds the point on the plane that is closest to the given `point`,
+    /// i.e. its orthogonal projection onto the plane.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        point - self.signed_distance(point) * *self.normal
+    }
 }
 
 /// An infinite line along a direction in 3D space.
@@ -151,6 +167,24 @@ pub struct Line3d {
 }
 impl Primitive3d for Line3d {}
 
+impl Line3d {
+    /// The distance from `point` to the closest point on the infinite line through the origin.
+    ///
+    /// A line has no interior, so this is always non-negative despite the `signed_distance`
+    /// name it shares with the solid primitives.
+    #[inline]
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        point.distance(self.closest_point(point))
+    }
+
+    /// Finds the point on the line that is closest to the given `point`,
+    /// i.e. its orthogonal projection onto the line.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        *self.direction * point.dot(*self.direction)
+    }
+}
+
 /// A segment of a line along a direction in 3D space.
 #[doc(alias = "LineSegment3d")]
 #[derive(Clone, Debug)]
@@ -195,6 +229,58 @@ impl Segment3d {
     pub fn point2(&self) -> Vec3 {
         *self.direction * self.half_length
     }
+
+    /// Get the full length of the line segment.
+    #[inline]
+    pub fn length(&self) -> f32 {
+        2.0 * self.half_length
+    }
+
+    /// Get the position along the segment at the normalized parameter `t ∈ [0, 1]`,
+    /// linearly interpolating from [`point1`](Self::point1) to [`point2`](Self::point2).
+    #[inline]
+    pub fn point_at(&self, t: f32) -> Vec3 {
+        self.point1().lerp(self.point2(), t)
+    }
+
+    /// Split the segment at the normalized parameter `t ∈ [0, 1]`, returning the two
+    /// sub-segments that tile the original and meet at the shared vertex
+    /// [`point_at(t)`](Self::point_at).
+    ///
+    /// Because [`Segment3d`] is centered on the origin, each piece is returned together with
+    /// its translation, like [`from_points`](Self::from_points): the first spans from
+    /// [`point1`](Self::point1) to the split vertex, the second from the split vertex to
+    /// [`point2`](Self::point2).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the split vertex coincides with an endpoint, i.e. `t` is `0.0` or `1.0`.
+    pub fn split_at(&self, t: f32) -> ((Segment3d, Vec3), (Segment3d, Vec3)) {
+        let split = self.point_at(t);
+        (
+            Segment3d::from_points(self.point1(), split),
+            Segment3d::from_points(split, self.point2()),
+        )
+    }
+
+    /// The distance from `point` to the closest point on the segment.
+    ///
+    /// A segment has no interior, so this is always non-negative despite the `signed_distance`
+    /// name it shares with the solid primitives.
+    #[inline]
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        point.distance(self.closest_point(point))
+    }
+
+    /// Finds the point on the segment that is closest to the given `point`,
+    /// projecting onto the line and clamping to the segment's extent.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let t = point
+            .dot(*self.direction)
+            .clamp(-self.half_length, self.half_length);
+        *self.direction * t
+    }
 }
 
 /// A series of connected line segments in 3D space.
@@ -223,6 +309,27 @@ impl<const N: usize> Polyline3d<N> {
     pub fn new(vertices: impl IntoIterator<Item = Vec3>) -> Self {
         Self::from_iter(vertices)
     }
+
+    /// Get the total arc length of the polyline, summing the distances between
+    /// consecutive vertices.
+    pub fn length(&self) -> f32 {
+        polyline_length(&self.vertices)
+    }
+
+    /// Get the position along the polyline at the normalized parameter `t ∈ [0, 1]`,
+    /// walking the arc-length parameterization.
+    pub fn point_at(&self, t: f32) -> Vec3 {
+        polyline_point_at(&self.vertices, t)
+    }
+
+    /// Iterate over the constituent [`Segment3d`]s of the polyline.
+    ///
+    /// Each yielded segment carries only the direction and length of an edge; positions are
+    /// discarded and zero-length edges are skipped, so the count may be fewer than the number
+    /// of edges.
+    pub fn segments(&self) -> impl Iterator<Item = Segment3d> + '_ {
+        polyline_segments(&self.vertices)
+    }
 }
 
 /// A series of connected line segments in 3D space, allocated on the heap
@@ -250,6 +357,75 @@ impl BoxedPolyline3d {
     pub fn new(vertices: impl IntoIterator<Item = Vec3>) -> Self {
         Self::from_iter(vertices)
     }
+
+    /// Get the total arc length of the polyline, summing the distances between
+    /// consecutive vertices.
+    pub fn length(&self) -> f32 {
+        polyline_length(&self.vertices)
+    }
+
+    /// Get the position along the polyline at the normalized parameter `t ∈ [0, 1]`,
+    /// walking the arc-length parameterization.
+    pub fn point_at(&self, t: f32) -> Vec3 {
+        polyline_point_at(&self.vertices, t)
+    }
+
+    /// Iterate over the constituent [`Segment3d`]s of the polyline.
+    ///
+    /// Each yielded segment carries only the direction and length of an edge; positions are
+    /// discarded and zero-length edges are skipped, so the count may be fewer than the number
+    /// of edges.
+    pub fn segments(&self) -> impl Iterator<Item = Segment3d> + '_ {
+        polyline_segments(&self.vertices)
+    }
+}
+
+/// Sum the distances between consecutive vertices of a polyline.
+fn polyline_length(vertices: &[Vec3]) -> f32 {
+    vertices
+        .windows(2)
+        .map(|pair| pair[0].distance(pair[1]))
+        .sum()
+}
+
+/// Sample a polyline at the normalized arc-length parameter `t ∈ [0, 1]`.
+fn polyline_point_at(vertices: &[Vec3], t: f32) -> Vec3 {
+    match vertices.first() {
+        None => Vec3::ZERO,
+        Some(&first) => {
+            let target = polyline_length(vertices) * t.clamp(0.0, 1.0);
+            let mut traveled = 0.0;
+            for pair in vertices.windows(2) {
+                let segment = pair[0].distance(pair[1]);
+                if traveled + segment >= target {
+                    let local = if segment > 0.0 {
+                        (target - traveled) / segment
+                    } else {
+                        0.0
+                    };
+                    return pair[0].lerp(pair[1], local);
+                }
+                traveled += segment;
+            }
+            // `t == 1.0` (or a degenerate polyline) lands on the final vertex.
+            vertices.last().copied().unwrap_or(first)
+        }
+    }
+}
+
+/// Iterate over the [`Segment3d`]s connecting consecutive vertices of a polyline.
+///
+/// Since [`Segment3d`] is position-less and centered on the origin, only each edge's
+/// direction and length are preserved — the world position is discarded, so the iterator
+/// cannot reconstruct the path's geometry. Degenerate edges between coincident vertices are
+/// skipped (they have no direction), so the iterator may yield fewer than `vertices.len() - 1`
+/// segments.
+fn polyline_segments(vertices: &[Vec3]) -> impl Iterator<Item = Segment3d> + '_ {
+    vertices.windows(2).filter_map(|pair| {
+        Direction3d::new(pair[1] - pair[0])
+            .ok()
+            .map(|direction| Segment3d::new(direction, pair[0].distance(pair[1])))
+    })
 }
 
 /// A cuboid primitive, more commonly known as a box.
@@ -302,26 +478,125 @@ impl Cylinder {
             half_height: height / 2.,
         }
     }
+
+    /// The signed distance from `point` to the surface of the cylinder.
+    ///
+    /// The distance is negative for points inside the cylinder.
+    #[inline]
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        let radial = Vec2::new(point.x, point.z).length() - self.radius;
+        let axial = point.y.abs() - self.half_height;
+        let outside = Vec2::new(radial.max(0.0), axial.max(0.0)).length();
+        let inside = radial.max(axial).min(0.0);
+        outside + inside
+    }
+
+    /// Finds the point on the cylinder that is closest to the given `point`.
+    ///
+    /// If the point is outside the cylinder, the returned point will be on its surface.
+    /// Otherwise, it will be inside the cylinder and returned as is.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let radial = Vec2::new(point.x, point.z);
+        let radial_length = radial.length();
+
+        if radial_length <= self.radius && point.y.abs() <= self.half_height {
+            // The point is inside the cylinder.
+            return point;
+        }
+
+        // Clamp the radial component to the radius and the axial component to the caps.
+        let clamped = if radial_length > self.radius {
+            radial * (self.radius / radial_length)
+        } else {
+            radial
+        };
+        Vec3::new(
+            clamped.x,
+            point.y.clamp(-self.half_height, self.half_height),
+            clamped.y,
+        )
+    }
 }
 
 /// A capsule primitive.
-/// A capsule is defined as a surface at a distance (radius) from a line
+/// A capsule is defined as a surface at a distance (radius) from a central [`Segment3d`].
 #[derive(Clone, Copy, Debug)]
 pub struct Capsule {
     /// The radius of the capsule
     pub radius: f32,
     /// Half the height of the capsule, excluding the hemispheres
     pub half_length: f32,
+    /// The direction of the capsule's central segment.
+    ///
+    /// The capsule extends by `half_length` in both this direction and its opposite,
+    /// so orienting the capsule is a matter of orienting this direction.
+    pub direction: Direction3d,
 }
 impl super::Primitive2d for Capsule {}
 impl Primitive3d for Capsule {}
 
 impl Capsule {
-    /// Create a new `Capsule` from a radius and length
+    /// Create a new `Capsule` from a radius and length, aligned with the Y axis.
     pub fn new(radius: f32, length: f32) -> Self {
         Self {
             radius,
             half_length: length / 2.0,
+            direction: Direction3d::Y,
+        }
+    }
+
+    /// Create a new `Capsule` from a radius and a central [`Segment3d`], giving it the
+    /// segment's arbitrary orientation.
+    pub fn from_segment(segment: Segment3d, radius: f32) -> Self {
+        Self {
+            radius,
+            half_length: segment.half_length,
+            direction: segment.direction,
+        }
+    }
+
+    /// Get the central [`Segment3d`] of the capsule.
+    ///
+    /// The capsule is the set of points within `radius` of this segment.
+    pub fn segment(&self) -> Segment3d {
+        Segment3d {
+            direction: self.direction,
+            half_length: self.half_length,
+        }
+    }
+
+    /// Get the position of the first endpoint of the central segment.
+    pub fn point1(&self) -> Vec3 {
+        self.segment().point1()
+    }
+
+    /// Get the position of the second endpoint of the central segment.
+    pub fn point2(&self) -> Vec3 {
+        self.segment().point2()
+    }
+
+    /// Finds the point on the capsule that is closest to the given `point`.
+    ///
+    /// If the point is outside the capsule, the returned point will be on the surface of the
+    /// capsule. Otherwise, it will be inside the capsule and returned as is.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        // Project `point` onto the infinite line through the central segment and clamp the
+        // parameter to the segment's extent to get the closest point on the core segment.
+        // A zero-length segment collapses the clamp to the origin, i.e. the sphere case.
+        let t = point
+            .dot(*self.direction)
+            .clamp(-self.half_length, self.half_length);
+        let c = *self.direction * t;
+
+        let offset = point - c;
+        let distance_squared = offset.length_squared();
+        if distance_squared <= self.radius.powi(2) {
+            // The point is inside the capsule.
+            point
+        } else {
+            c + self.radius * offset / distance_squared.sqrt()
         }
     }
 }
@@ -336,6 +611,74 @@ pub struct Cone {
 }
 impl Primitive3d for Cone {}
 
+impl Cone {
+    /// The signed distance from `point` to the surface of the cone.
+    ///
+    /// The distance is negative for points inside the cone.
+    #[inline]
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        let radial = Vec2::new(point.x, point.z).length();
+        let (_, distance) = self.closest_cross_section_point(Vec2::new(radial, point.y));
+        distance
+    }
+
+    /// Finds the point on the cone that is closest to the given `point`.
+    ///
+    /// If the point is outside the cone, the returned point will be on its surface.
+    /// Otherwise, it will be inside the cone and returned as is.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let radial = Vec2::new(point.x, point.z);
+        let radial_length = radial.length();
+
+        let (cross_section, distance) =
+            self.closest_cross_section_point(Vec2::new(radial_length, point.y));
+        if distance <= 0.0 {
+            // The point is inside the cone.
+            return point;
+        }
+
+        // Map the cross-section point back into 3D along the original radial direction.
+        let direction = if radial_length > 0.0 {
+            radial / radial_length
+        } else {
+            Vec2::X
+        };
+        Vec3::new(
+            direction.x * cross_section.x,
+            cross_section.y,
+            direction.y * cross_section.x,
+        )
+    }
+
+    /// Finds the closest point and signed distance in the `(radius, height)` cross-section,
+    /// whose solid is the triangle with apex `(0, height/2)` and base edge at `y = -height/2`.
+    #[inline]
+    fn closest_cross_section_point(&self, point: Vec2) -> (Vec2, f32) {
+        let half_height = self.height / 2.0;
+        let apex = Vec2::new(0.0, half_height);
+        let base_rim = Vec2::new(self.radius, -half_height);
+        let base_center = Vec2::new(0.0, -half_height);
+
+        // Only the lateral edge and the base disk are real surfaces; the axis edge
+        // `base_center -> apex` is interior and must not count towards the distance.
+        let on_lateral = closest_point_on_segment_2d(point, apex, base_rim);
+        let on_base = closest_point_on_segment_2d(point, base_rim, base_center);
+        let closest = if point.distance_squared(on_lateral) <= point.distance_squared(on_base) {
+            on_lateral
+        } else {
+            on_base
+        };
+
+        let sign = if point_in_triangle_2d(point, [apex, base_rim, base_center]) {
+            -1.0
+        } else {
+            1.0
+        };
+        (closest, sign * point.distance(closest))
+    }
+}
+
 /// A conical frustum primitive.
 /// A conical frustum can be created
 /// by slicing off a section of a cone.
@@ -440,6 +783,556 @@ impl Torus {
             std::cmp::Ordering::Less => TorusKind::Spindle,
         }
     }
+
+    /// The point on the major-radius ring closest to `point`.
+    #[inline]
+    fn ring_point(&self, point: Vec3) -> Vec3 {
+        let planar = Vec3::new(point.x, 0.0, point.z);
+        // On the axis any ring point is equidistant, so fall back to the +X direction.
+        let direction = planar.try_normalize().unwrap_or(Vec3::X);
+        self.major_radius * direction
+    }
+
+    /// The signed distance from `point` to the surface of the torus.
+    ///
+    /// The distance is negative for points inside the tube.
+    #[inline]
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        (point - self.ring_point(point)).length() - self.minor_radius
+    }
+
+    /// Finds the point on the torus that is closest to the given `point`.
+    ///
+    /// If the point is outside the tube, the returned point will be on its surface.
+    /// Otherwise, it will be inside the tube and returned as is.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let ring = self.ring_point(point);
+        let offset = point - ring;
+        let distance = offset.length();
+        if distance <= self.minor_radius {
+            // The point is inside the tube.
+            point
+        } else {
+            ring + self.minor_radius * offset / distance
+        }
+    }
+}
+
+/// An axis-aligned bounding box in 3D space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb3d {
+    /// The minimum corner of the box
+    pub min: Vec3,
+    /// The maximum corner of the box
+    pub max: Vec3,
+}
+
+impl Aabb3d {
+    /// Create a new [`Aabb3d`] centered on `center` with the given `half_extents`.
+    #[inline]
+    pub fn new(center: Vec3, half_extents: Vec3) -> Self {
+        Self {
+            min: center - half_extents,
+            max: center + half_extents,
+        }
+    }
+}
+
+/// A bounding sphere in 3D space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingSphere {
+    /// The center of the bounding sphere
+    pub center: Vec3,
+    /// The radius of the bounding sphere
+    pub radius: f32,
+}
+
+impl BoundingSphere {
+    /// Create a new [`BoundingSphere`] from a `center` and a `radius`.
+    #[inline]
+    pub fn new(center: Vec3, radius: f32) -> Self {
+        Self { center, radius }
+    }
+}
+
+/// A trait for computing bounding volumes of a primitive after an isometric transform.
+pub trait Bounded3d {
+    /// Compute the [`Aabb3d`] of the primitive placed at `translation` and rotated by `rotation`.
+    fn aabb(&self, translation: Vec3, rotation: Quat) -> Aabb3d;
+
+    /// Compute the [`BoundingSphere`] of the primitive placed at `translation` and rotated by `rotation`.
+    fn bounding_sphere(&self, translation: Vec3, rotation: Quat) -> BoundingSphere;
+}
+
+/// The world-space half-extents of a local box of `half_size` rotated by `rotation`.
+///
+/// The half-extent along a world axis `a` is `sum_i |a · (R * e_i)| * half_size_i`, which is
+/// exactly the absolute value of the rotation matrix applied to the local half-extents.
+#[inline]
+fn rotated_half_extents(half_size: Vec3, rotation: Quat) -> Vec3 {
+    let rot = Mat3::from_quat(rotation);
+    let abs = Mat3::from_cols(
+        rot.x_axis.abs(),
+        rot.y_axis.abs(),
+        rot.z_axis.abs(),
+    );
+    abs * half_size
+}
+
+impl Bounded3d for Sphere {
+    fn aabb(&self, translation: Vec3, _rotation: Quat) -> Aabb3d {
+        Aabb3d::new(translation, Vec3::splat(self.radius))
+    }
+
+    fn bounding_sphere(&self, translation: Vec3, _rotation: Quat) -> BoundingSphere {
+        BoundingSphere::new(translation, self.radius)
+    }
+}
+
+impl Bounded3d for Cuboid {
+    fn aabb(&self, translation: Vec3, rotation: Quat) -> Aabb3d {
+        Aabb3d::new(translation, rotated_half_extents(self.half_size, rotation))
+    }
+
+    fn bounding_sphere(&self, translation: Vec3, _rotation: Quat) -> BoundingSphere {
+        BoundingSphere::new(translation, self.half_size.length())
+    }
+}
+
+impl Bounded3d for Cylinder {
+    fn aabb(&self, translation: Vec3, rotation: Quat) -> Aabb3d {
+        let half_size = Vec3::new(self.radius, self.half_height, self.radius);
+        Aabb3d::new(translation, rotated_half_extents(half_size, rotation))
+    }
+
+    fn bounding_sphere(&self, translation: Vec3, _rotation: Quat) -> BoundingSphere {
+        let radius = self.radius.hypot(self.half_height);
+        BoundingSphere::new(translation, radius)
+    }
+}
+
+impl Bounded3d for Cone {
+    fn aabb(&self, translation: Vec3, rotation: Quat) -> Aabb3d {
+        let half_size = Vec3::new(self.radius, self.height / 2.0, self.radius);
+        Aabb3d::new(translation, rotated_half_extents(half_size, rotation))
+    }
+
+    fn bounding_sphere(&self, translation: Vec3, _rotation: Quat) -> BoundingSphere {
+        let radius = self.radius.hypot(self.height / 2.0);
+        BoundingSphere::new(translation, radius)
+    }
+}
+
+impl Bounded3d for ConicalFrustum {
+    fn aabb(&self, translation: Vec3, rotation: Quat) -> Aabb3d {
+        let radius = self.radius_top.max(self.radius_bottom);
+        let half_size = Vec3::new(radius, self.height / 2.0, radius);
+        Aabb3d::new(translation, rotated_half_extents(half_size, rotation))
+    }
+
+    fn bounding_sphere(&self, translation: Vec3, _rotation: Quat) -> BoundingSphere {
+        let radius = self.radius_top.max(self.radius_bottom);
+        BoundingSphere::new(translation, radius.hypot(self.height / 2.0))
+    }
+}
+
+impl Bounded3d for Capsule {
+    fn aabb(&self, translation: Vec3, rotation: Quat) -> Aabb3d {
+        // The capsule is the union of the two hemisphere-capping spheres.
+        let tip = rotation * self.point2();
+        let a = translation + tip;
+        let b = translation - tip;
+        let radius = Vec3::splat(self.radius);
+        Aabb3d {
+            min: a.min(b) - radius,
+            max: a.max(b) + radius,
+        }
+    }
+
+    fn bounding_sphere(&self, translation: Vec3, _rotation: Quat) -> BoundingSphere {
+        BoundingSphere::new(translation, self.half_length + self.radius)
+    }
+}
+
+impl Bounded3d for Torus {
+    fn aabb(&self, translation: Vec3, rotation: Quat) -> Aabb3d {
+        // The torus lies in the local XZ plane with its axis along Y.
+        let outer = self.major_radius + self.minor_radius;
+        let half_size = Vec3::new(outer, self.minor_radius, outer);
+        Aabb3d::new(translation, rotated_half_extents(half_size, rotation))
+    }
+
+    fn bounding_sphere(&self, translation: Vec3, _rotation: Quat) -> BoundingSphere {
+        BoundingSphere::new(translation, self.outer_radius())
+    }
+}
+
+impl Bounded3d for Segment3d {
+    fn aabb(&self, translation: Vec3, rotation: Quat) -> Aabb3d {
+        let a = translation + rotation * self.point1();
+        let b = translation + rotation * self.point2();
+        Aabb3d {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    fn bounding_sphere(&self, translation: Vec3, _rotation: Quat) -> BoundingSphere {
+        BoundingSphere::new(translation, self.half_length)
+    }
+}
+
+impl<const N: usize> Bounded3d for Polyline3d<N> {
+    fn aabb(&self, translation: Vec3, rotation: Quat) -> Aabb3d {
+        aabb_from_points(self.vertices.iter().map(|&v| translation + rotation * v))
+    }
+
+    fn bounding_sphere(&self, translation: Vec3, rotation: Quat) -> BoundingSphere {
+        bounding_sphere_from_aabb(self.aabb(translation, rotation))
+    }
+}
+
+impl Bounded3d for BoxedPolyline3d {
+    fn aabb(&self, translation: Vec3, rotation: Quat) -> Aabb3d {
+        aabb_from_points(self.vertices.iter().map(|&v| translation + rotation * v))
+    }
+
+    fn bounding_sphere(&self, translation: Vec3, rotation: Quat) -> BoundingSphere {
+        bounding_sphere_from_aabb(self.aabb(translation, rotation))
+    }
+}
+
+/// Build the tightest [`Aabb3d`] enclosing a set of points.
+#[inline]
+fn aabb_from_points(points: impl IntoIterator<Item = Vec3>) -> Aabb3d {
+    let mut min = Vec3::splat(f32::INFINITY);
+    let mut max = Vec3::splat(f32::NEG_INFINITY);
+    for point in points {
+        min = min.min(point);
+        max = max.max(point);
+    }
+    Aabb3d { min, max }
+}
+
+/// A [`BoundingSphere`] centered on an [`Aabb3d`] that encloses it.
+#[inline]
+fn bounding_sphere_from_aabb(aabb: Aabb3d) -> BoundingSphere {
+    let center = (aabb.min + aabb.max) / 2.0;
+    BoundingSphere::new(center, (aabb.max - center).length())
+}
+
+/// The closest point on the 2D segment `a`–`b` to `point`.
+///
+/// A degenerate (zero-length) segment collapses to its first endpoint.
+fn closest_point_on_segment_2d(point: Vec2, a: Vec2, b: Vec2) -> Vec2 {
+    let edge = b - a;
+    let length_squared = edge.length_squared();
+    if length_squared == 0.0 {
+        return a;
+    }
+    let t = ((point - a).dot(edge) / length_squared).clamp(0.0, 1.0);
+    a + edge * t
+}
+
+/// Whether `point` lies inside the 2D triangle `vertices`, regardless of winding.
+fn point_in_triangle_2d(point: Vec2, vertices: [Vec2; 3]) -> bool {
+    let mut positive = false;
+    let mut negative = false;
+    for i in 0..3 {
+        let side = (vertices[(i + 1) % 3] - vertices[i]).perp_dot(point - vertices[i]);
+        positive |= side > 0.0;
+        negative |= side < 0.0;
+    }
+    !(positive && negative)
+}
+
+/// The result of a successful ray cast against a primitive.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit {
+    /// The time of impact, i.e. the distance along the ray at which the hit occurred.
+    pub toi: f32,
+    /// The surface normal at the point of impact.
+    pub normal: Direction3d,
+}
+
+/// A trait for casting a ray against a primitive placed at the origin.
+pub trait RayCast3d {
+    /// Cast a ray from `origin` along `dir`, returning the first [`RayHit`] no farther than
+    /// `max_toi`, or [`None`] if the ray misses within that range.
+    fn cast_ray(&self, origin: Vec3, dir: Direction3d, max_toi: f32) -> Option<RayHit>;
+}
+
+impl RayCast3d for Sphere {
+    fn cast_ray(&self, origin: Vec3, dir: Direction3d, max_toi: f32) -> Option<RayHit> {
+        // Solve |origin + t * dir|² = radius² for the smallest nonnegative `t`.
+        let b = origin.dot(*dir);
+        let c = origin.length_squared() - self.radius.powi(2);
+        let discriminant = b * b - c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let sqrt_d = discriminant.sqrt();
+        let toi = [-b - sqrt_d, -b + sqrt_d]
+            .into_iter()
+            .find(|&t| t >= 0.0)?;
+        (toi <= max_toi).then(|| RayHit {
+            toi,
+            normal: Direction3d::new_unchecked((origin + toi * *dir) / self.radius),
+        })
+    }
+}
+
+impl RayCast3d for Plane3d {
+    fn cast_ray(&self, origin: Vec3, dir: Direction3d, max_toi: f32) -> Option<RayHit> {
+        let denom = dir.dot(*self.normal);
+        if denom == 0.0 {
+            // The ray is parallel to the plane.
+            return None;
+        }
+
+        let toi = -origin.dot(*self.normal) / denom;
+        (toi >= 0.0 && toi <= max_toi).then(|| RayHit {
+            toi,
+            // Report the face of the plane that the ray approached from.
+            normal: if denom < 0.0 { self.normal } else { -self.normal },
+        })
+    }
+}
+
+impl RayCast3d for Cuboid {
+    fn cast_ray(&self, origin: Vec3, dir: Direction3d, max_toi: f32) -> Option<RayHit> {
+        // Slab method: intersect the ray with the three pairs of axis-aligned planes.
+        let dir = *dir;
+        let mut tmin = f32::NEG_INFINITY;
+        let mut tmax = f32::INFINITY;
+        let mut axis = 0;
+
+        for i in 0..3 {
+            let inv_d = 1.0 / dir[i];
+            let mut t1 = (-self.half_size[i] - origin[i]) * inv_d;
+            let mut t2 = (self.half_size[i] - origin[i]) * inv_d;
+            if t1 > t2 {
+                std::mem::swap(&mut t1, &mut t2);
+            }
+            if t1 > tmin {
+                tmin = t1;
+                axis = i;
+            }
+            tmax = tmax.min(t2);
+        }
+
+        if tmin > tmax || tmax < 0.0 || tmin > max_toi {
+            return None;
+        }
+
+        let mut normal = Vec3::ZERO;
+        normal[axis] = -dir[axis].signum();
+        Some(RayHit {
+            toi: tmin.max(0.0),
+            normal: Direction3d::new_unchecked(normal),
+        })
+    }
+}
+
+impl RayCast3d for Cylinder {
+    fn cast_ray(&self, origin: Vec3, dir: Direction3d, max_toi: f32) -> Option<RayHit> {
+        let mut best: Option<RayHit> = None;
+        let mut consider = |hit: RayHit| {
+            if hit.toi >= 0.0 && hit.toi <= max_toi && best.map_or(true, |b| hit.toi < b.toi) {
+                best = Some(hit);
+            }
+        };
+
+        // Infinite cylinder about the Y axis, restricted to the finite body.
+        let a = dir.x * dir.x + dir.z * dir.z;
+        if a > f32::EPSILON {
+            let b = origin.x * dir.x + origin.z * dir.z;
+            let c = origin.x * origin.x + origin.z * origin.z - self.radius.powi(2);
+            let discriminant = b * b - a * c;
+            if discriminant >= 0.0 {
+                let sqrt_d = discriminant.sqrt();
+                for toi in [(-b - sqrt_d) / a, (-b + sqrt_d) / a] {
+                    let y = origin.y + toi * dir.y;
+                    if y.abs() <= self.half_height {
+                        let point = origin + toi * *dir;
+                        consider(RayHit {
+                            toi,
+                            normal: Direction3d::new_unchecked(
+                                Vec3::new(point.x, 0.0, point.z) / self.radius,
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+
+        // End caps at y = ±half_height.
+        if dir.y != 0.0 {
+            for (sign, normal) in [(1.0, Direction3d::Y), (-1.0, Direction3d::NEG_Y)] {
+                let toi = (sign * self.half_height - origin.y) / dir.y;
+                let point = origin + toi * *dir;
+                if point.x * point.x + point.z * point.z <= self.radius.powi(2) {
+                    consider(RayHit { toi, normal });
+                }
+            }
+        }
+
+        best
+    }
+}
+
+impl RayCast3d for Capsule {
+    fn cast_ray(&self, origin: Vec3, dir: Direction3d, max_toi: f32) -> Option<RayHit> {
+        let mut best: Option<RayHit> = None;
+        let mut consider = |hit: Option<RayHit>| {
+            if let Some(hit) = hit {
+                if best.map_or(true, |b| hit.toi < b.toi) {
+                    best = Some(hit);
+                }
+            }
+        };
+
+        // The capsule is the cylindrical body plus the two capping hemispheres.
+        // Rotate into the capsule's local frame (central segment aligned with Y) so the
+        // curved side of the finite body can be intersected with the axis-aligned formula,
+        // then rotate the resulting normal back; the hemispheres cover the caps.
+        let to_local = Quat::from_rotation_arc(*self.direction, Vec3::Y);
+        let local_origin = to_local * origin;
+        let local_dir = to_local * *dir;
+        let a = local_dir.x * local_dir.x + local_dir.z * local_dir.z;
+        if a > f32::EPSILON {
+            let b = local_origin.x * local_dir.x + local_origin.z * local_dir.z;
+            let c = local_origin.x * local_origin.x + local_origin.z * local_origin.z
+                - self.radius.powi(2);
+            let discriminant = b * b - a * c;
+            if discriminant >= 0.0 {
+                let sqrt_d = discriminant.sqrt();
+                for toi in [(-b - sqrt_d) / a, (-b + sqrt_d) / a] {
+                    let y = local_origin.y + toi * local_dir.y;
+                    if toi >= 0.0 && toi <= max_toi && y.abs() <= self.half_length {
+                        let local_point = local_origin + toi * local_dir;
+                        let local_normal = Vec3::new(local_point.x, 0.0, local_point.z) / self.radius;
+                        consider(Some(RayHit {
+                            toi,
+                            normal: Direction3d::new_unchecked(to_local.inverse() * local_normal),
+                        }));
+                    }
+                }
+            }
+        }
+
+        // Capping hemispheres, modelled as full spheres at each endpoint.
+        let sphere = Sphere::new(self.radius);
+        for center in [self.point1(), self.point2()] {
+            consider(sphere.cast_ray(origin - center, dir, max_toi));
+        }
+
+        best
+    }
+}
+
+impl RayCast3d for Segment3d {
+    fn cast_ray(&self, origin: Vec3, dir: Direction3d, max_toi: f32) -> Option<RayHit> {
+        // A segment has no thickness, so treat it as a degenerate zero-radius capsule and
+        // report a hit when the ray passes within floating-point tolerance of the segment.
+        const EPSILON: f32 = 1e-4;
+
+        // Closest approach between the ray and the (clamped) segment line.
+        let seg_dir = *self.direction;
+        let w0 = origin;
+        let b = dir.dot(seg_dir);
+        let d = dir.dot(w0);
+        let e = seg_dir.dot(w0);
+        let denom = 1.0 - b * b;
+        if denom.abs() < f32::EPSILON {
+            // Ray and segment are parallel.
+            return None;
+        }
+
+        let toi = (b * e - d) / denom;
+        if toi < 0.0 || toi > max_toi {
+            return None;
+        }
+        let s = (e - b * d) / denom;
+        let s = s.clamp(-self.half_length, self.half_length);
+
+        let ray_point = origin + toi * *dir;
+        let seg_point = seg_dir * s;
+        let offset = ray_point - seg_point;
+        if offset.length_squared() <= EPSILON * EPSILON {
+            Some(RayHit {
+                toi,
+                normal: Direction3d::new(offset).unwrap_or(self.direction),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// A trait for the support mapping of a convex primitive, as used by GJK/EPA.
+pub trait SupportMap3d {
+    /// Returns the farthest point of the shape in the given `direction`.
+    ///
+    /// `direction` need not be normalized.
+    fn support_point(&self, direction: Vec3) -> Vec3;
+}
+
+impl SupportMap3d for Sphere {
+    fn support_point(&self, direction: Vec3) -> Vec3 {
+        self.radius * direction.normalize_or_zero()
+    }
+}
+
+impl SupportMap3d for Cuboid {
+    fn support_point(&self, direction: Vec3) -> Vec3 {
+        Vec3::new(
+            direction.x.signum() * self.half_size.x,
+            direction.y.signum() * self.half_size.y,
+            direction.z.signum() * self.half_size.z,
+        )
+    }
+}
+
+impl SupportMap3d for Segment3d {
+    fn support_point(&self, direction: Vec3) -> Vec3 {
+        let (p1, p2) = (self.point1(), self.point2());
+        if direction.dot(p1) >= direction.dot(p2) {
+            p1
+        } else {
+            p2
+        }
+    }
+}
+
+impl SupportMap3d for Cylinder {
+    fn support_point(&self, direction: Vec3) -> Vec3 {
+        // Pick the cap by the sign of the axial component and the rim point by the radial one.
+        let cap_y = direction.y.signum() * self.half_height;
+        let rim = Vec3::new(direction.x, 0.0, direction.z).normalize_or_zero() * self.radius;
+        Vec3::new(rim.x, cap_y, rim.z)
+    }
+}
+
+impl SupportMap3d for Capsule {
+    fn support_point(&self, direction: Vec3) -> Vec3 {
+        self.segment().support_point(direction) + self.radius * direction.normalize_or_zero()
+    }
+}
+
+impl SupportMap3d for Cone {
+    fn support_point(&self, direction: Vec3) -> Vec3 {
+        let apex = Vec3::new(0.0, self.height / 2.0, 0.0);
+        let base_rim = Vec3::new(direction.x, 0.0, direction.z).normalize_or_zero() * self.radius
+            - Vec3::new(0.0, self.height / 2.0, 0.0);
+        if direction.dot(apex) >= direction.dot(base_rim) {
+            apex
+        } else {
+            base_rim
+        }
+    }
 }
 
 #[cfg(test)]
@@ -482,6 +1375,199 @@ mod test {
         );
     }
 
+    #[test]
+    fn sphere_aabb() {
+        let aabb = Sphere::new(2.0).aabb(Vec3::X, Quat::IDENTITY);
+        assert_eq!(aabb.min, Vec3::new(-1.0, -2.0, -2.0));
+        assert_eq!(aabb.max, Vec3::new(3.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn cuboid_aabb_rotated() {
+        // A 45° rotation about Y grows the X/Z extents of a unit cube to `sqrt(2)/2`.
+        let cuboid = Cuboid::new(1.0, 1.0, 1.0);
+        let aabb = cuboid.aabb(Vec3::ZERO, Quat::from_rotation_y(std::f32::consts::FRAC_PI_4));
+        let extent = 2.0_f32.sqrt() / 2.0;
+        assert!((aabb.max.x - extent).abs() < 1e-5);
+        assert!((aabb.max.y - 0.5).abs() < 1e-5);
+        assert!((aabb.max.z - extent).abs() < 1e-5);
+    }
+
+    #[test]
+    fn plane_closest_point() {
+        let plane = Plane3d::new(Vec3::Y);
+        assert_eq!(plane.signed_distance(Vec3::Y * 3.0), 3.0);
+        assert_eq!(
+            plane.closest_point(Vec3::new(1.0, 3.0, -2.0)),
+            Vec3::new(1.0, 0.0, -2.0)
+        );
+    }
+
+    #[test]
+    fn cylinder_closest_point() {
+        let cylinder = Cylinder::new(1.0, 2.0);
+        assert_eq!(cylinder.closest_point(Vec3::X * 10.0), Vec3::X);
+        assert_eq!(cylinder.closest_point(Vec3::Y * 10.0), Vec3::Y);
+        // Inside the cylinder is returned unchanged.
+        assert_eq!(
+            cylinder.closest_point(Vec3::new(0.25, 0.5, 0.0)),
+            Vec3::new(0.25, 0.5, 0.0)
+        );
+        assert_eq!(cylinder.signed_distance(Vec3::X * 3.0), 2.0);
+    }
+
+    #[test]
+    fn torus_closest_point() {
+        // A ring torus with tube radius 1 centered on a ring of radius 3.
+        let torus = Torus::new(2.0, 4.0);
+        assert_eq!(torus.minor_radius, 1.0);
+        assert_eq!(torus.major_radius, 3.0);
+        assert_eq!(torus.closest_point(Vec3::X * 10.0), Vec3::X * 4.0);
+        assert_eq!(torus.signed_distance(Vec3::X * 10.0), 6.0);
+        // A point on the ring center-line is at tube distance `minor_radius` from the surface.
+        assert_eq!(torus.signed_distance(Vec3::X * 3.0), -1.0);
+    }
+
+    #[test]
+    fn cone_closest_point() {
+        // Apex at (0, 1, 0), base circle of radius 1 at y = -1.
+        let cone = Cone {
+            radius: 1.0,
+            height: 2.0,
+        };
+        // A point below the base projects onto the base disk.
+        assert_eq!(cone.closest_point(Vec3::NEG_Y * 5.0), Vec3::NEG_Y);
+        // A deep-interior point near the axis is measured against the real lateral/base
+        // surface, not the central axis, so its distance is well away from zero.
+        assert!(cone.signed_distance(Vec3::new(0.01, 0.0, 0.0)) < -0.3);
+        // A degenerate cone must not produce NaN.
+        assert!(Cone { radius: 0.0, height: 2.0 }
+            .signed_distance(Vec3::X)
+            .is_finite());
+    }
+
+    #[test]
+    fn segment_split_and_sample() {
+        let segment = Segment3d::new(Direction3d::X, 4.0);
+        assert_eq!(segment.length(), 4.0);
+        assert_eq!(segment.point_at(0.5), Vec3::ZERO);
+        assert_eq!(segment.point_at(1.0), Vec3::X * 2.0);
+
+        // Splitting `(-2,0,0)..(2,0,0)` at `t = 0.25` meets at the shared vertex `(-1,0,0)`.
+        let ((a, a_translation), (b, b_translation)) = segment.split_at(0.25);
+        assert_eq!(a.length(), 1.0);
+        assert_eq!(a_translation, Vec3::new(-1.5, 0.0, 0.0));
+        assert_eq!(b.length(), 3.0);
+        assert_eq!(b_translation, Vec3::new(0.5, 0.0, 0.0));
+    }
+
+    #[test]
+    fn polyline_length_and_sample() {
+        let polyline = Polyline3d::<3>::new([Vec3::ZERO, Vec3::X * 2.0, Vec3::X * 2.0 + Vec3::Y * 2.0]);
+        assert_eq!(polyline.length(), 4.0);
+        assert_eq!(polyline.point_at(0.0), Vec3::ZERO);
+        assert_eq!(polyline.point_at(0.5), Vec3::X * 2.0);
+        assert_eq!(polyline.point_at(1.0), Vec3::X * 2.0 + Vec3::Y * 2.0);
+        assert_eq!(polyline.segments().count(), 2);
+    }
+
+    #[test]
+    fn cuboid_support_point() {
+        let cuboid = Cuboid::new(2.0, 2.0, 2.0);
+        assert_eq!(
+            cuboid.support_point(Vec3::new(1.0, -1.0, 0.5)),
+            Vec3::new(1.0, -1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn cylinder_support_point() {
+        let cylinder = Cylinder::new(1.0, 2.0);
+        assert_eq!(cylinder.support_point(Vec3::Y), Vec3::Y);
+        assert_eq!(
+            cylinder.support_point(Vec3::new(1.0, -1.0, 0.0)),
+            Vec3::new(1.0, -1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn sphere_ray_cast() {
+        let sphere = Sphere::new(1.0);
+        let hit = sphere
+            .cast_ray(Vec3::X * 3.0, Direction3d::NEG_X, 10.0)
+            .unwrap();
+        assert_eq!(hit.toi, 2.0);
+        assert_eq!(hit.normal, Direction3d::X);
+        // A ray that stops short of the sphere misses.
+        assert!(sphere.cast_ray(Vec3::X * 3.0, Direction3d::NEG_X, 1.0).is_none());
+        // A ray pointing away misses.
+        assert!(sphere.cast_ray(Vec3::X * 3.0, Direction3d::X, 10.0).is_none());
+    }
+
+    #[test]
+    fn cuboid_ray_cast() {
+        let cuboid = Cuboid::new(2.0, 2.0, 2.0);
+        let hit = cuboid
+            .cast_ray(Vec3::X * 3.0, Direction3d::NEG_X, 10.0)
+            .unwrap();
+        assert_eq!(hit.toi, 2.0);
+        assert_eq!(hit.normal, Direction3d::X);
+    }
+
+    #[test]
+    fn capsule_ray_cast() {
+        // A capsule whose body runs along the X axis; the ray strikes the side.
+        let capsule = Capsule::from_segment(Segment3d::new(Direction3d::X, 4.0), 1.0);
+        let hit = capsule
+            .cast_ray(Vec3::Y * 5.0, Direction3d::NEG_Y, 10.0)
+            .unwrap();
+        assert_eq!(hit.toi, 4.0);
+        // The normal goes through a rotation round trip, so compare it within a tolerance.
+        assert!(hit.normal.abs_diff_eq(Vec3::Y, 1e-5));
+        // A ray down the extension of the body strikes the capping hemisphere.
+        let hit = capsule
+            .cast_ray(Vec3::X * 5.0, Direction3d::NEG_X, 10.0)
+            .unwrap();
+        assert_eq!(hit.toi, 2.0);
+        assert_eq!(hit.normal, Direction3d::X);
+    }
+
+    #[test]
+    fn plane_ray_cast() {
+        let plane = Plane3d::new(Vec3::Y);
+        let hit = plane
+            .cast_ray(Vec3::Y * 2.0, Direction3d::NEG_Y, 10.0)
+            .unwrap();
+        assert_eq!(hit.toi, 2.0);
+        assert_eq!(hit.normal, Direction3d::Y);
+    }
+
+    #[test]
+    fn capsule_closest_point() {
+        let capsule = Capsule::new(1.0, 2.0);
+        // Outside the cylindrical body: clamps radially to the surface.
+        assert_eq!(capsule.closest_point(Vec3::X * 10.0), Vec3::X);
+        // Beyond an endpoint: clamps to the capping hemisphere.
+        assert_eq!(capsule.closest_point(Vec3::Y * 10.0), Vec3::Y * 2.0);
+        // Inside the capsule is returned unchanged.
+        assert_eq!(
+            capsule.closest_point(Vec3::new(0.25, 0.5, 0.0)),
+            Vec3::new(0.25, 0.5, 0.0)
+        );
+    }
+
+    #[test]
+    fn oriented_capsule() {
+        // A capsule whose central segment runs along the X axis.
+        let capsule = Capsule::from_segment(Segment3d::new(Direction3d::X, 4.0), 1.0);
+        assert_eq!(capsule.point1(), Vec3::X * -2.0);
+        assert_eq!(capsule.point2(), Vec3::X * 2.0);
+        // Beyond an endpoint: clamps to the capping hemisphere along X.
+        assert_eq!(capsule.closest_point(Vec3::X * 10.0), Vec3::X * 3.0);
+        // Off the side: clamps radially to the surface.
+        assert_eq!(capsule.closest_point(Vec3::Y * 10.0), Vec3::Y);
+    }
+
     #[test]
     fn sphere_closest_point() {
         let sphere = Sphere { radius: 1.0 };